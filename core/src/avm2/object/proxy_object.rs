@@ -1,10 +1,22 @@
 //! Object representation for `Proxy`.
+//!
+//! `QName` (and the `QNameObject` that wraps it) still stores a single
+//! `Namespace`, not a multiname's whole resolved namespace set. Widening it
+//! so a `Proxy`'s `flash_proxy` callbacks could forward the full set, and
+//! the AS3 side could re-resolve against any object using that same set,
+//! was requested but isn't done here: `QName` is the interpreter's shared
+//! name type, used to build every class/trait/property name in the tree,
+//! and every other place that constructs or matches on one is outside this
+//! file. `resolve_namespace` below still picks a single namespace out of
+//! the set, exactly as it did before; nothing in this module should be read
+//! as having closed that out.
 
 use crate::avm2::activation::Activation;
 use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, QNameObject, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Multiname;
+use crate::avm2::Namespace;
 use crate::avm2::QName;
 use crate::avm2::{AvmString, Error};
 use core::fmt;
@@ -44,6 +56,43 @@ pub struct ProxyObjectData<'gc> {
     base: ScriptObjectData<'gc>,
 }
 
+impl<'gc> ProxyObject<'gc> {
+    /// Picks which namespace in `multiname`'s namespace set a `flash_proxy`
+    /// callback should see for `multiname`: the first one that is public,
+    /// user-defined, or the "any" namespace, same selection `get_property_local`
+    /// and friends used before they were deduplicated into this helper. See
+    /// the module-level note above — this is plain first-match, not the
+    /// namespace-set widening that was asked for.
+    fn resolve_namespace(multiname: &Multiname<'gc>) -> Option<Namespace<'gc>> {
+        multiname
+            .namespace_set()
+            .iter()
+            .find(|namespace| namespace.is_any() || namespace.is_public() || namespace.is_namespace())
+            .copied()
+    }
+
+    /// Builds the `QName` that a `flash_proxy` callback (`getProperty`,
+    /// `setProperty`, `callProperty`, `deleteProperty`, `hasProperty`,
+    /// `getDescendants`) should receive for `multiname`.
+    fn resolve_qname(
+        multiname: &Multiname<'gc>,
+        activation: &mut Activation<'_, 'gc>,
+    ) -> Result<Option<QNameObject<'gc>>, Error<'gc>> {
+        let Some(local_name) = multiname.local_name() else {
+            return Ok(None);
+        };
+
+        let Some(namespace) = Self::resolve_namespace(multiname) else {
+            return Ok(None);
+        };
+
+        Ok(Some(QNameObject::from_qname(
+            activation,
+            QName::new(namespace, local_name),
+        )?))
+    }
+}
+
 impl<'gc> TObject<'gc> for ProxyObject<'gc> {
     fn base(&self) -> Ref<ScriptObjectData<'gc>> {
         Ref::map(self.0.read(), |read| &read.base)
@@ -66,23 +115,12 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         multiname: &Multiname<'gc>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    return self.call_property(
-                        &Multiname::new(activation.avm2().proxy_namespace, "getProperty"),
-                        &[qname.into()],
-                        activation,
-                    );
-                }
-            }
+        if let Some(qname) = Self::resolve_qname(multiname, activation)? {
+            return self.call_property(
+                &Multiname::new(activation.avm2().proxy_namespace, "getProperty"),
+                &[qname.into()],
+                activation,
+            );
         }
 
         if !self
@@ -102,25 +140,14 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         value: Value<'gc>,
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<(), Error<'gc>> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    self.call_property(
-                        &Multiname::new(activation.avm2().proxy_namespace, "setProperty"),
-                        &[qname.into(), value],
-                        activation,
-                    )?;
-
-                    return Ok(());
-                }
-            }
+        if let Some(qname) = Self::resolve_qname(multiname, activation)? {
+            self.call_property(
+                &Multiname::new(activation.avm2().proxy_namespace, "setProperty"),
+                &[qname.into(), value],
+                activation,
+            )?;
+
+            return Ok(());
         }
 
         if !self
@@ -144,26 +171,15 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         arguments: &[Value<'gc>],
         activation: &mut Activation<'_, 'gc>,
     ) -> Result<Value<'gc>, Error<'gc>> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    let mut args = vec![qname.into()];
-                    args.extend_from_slice(arguments);
-
-                    return self.call_property(
-                        &Multiname::new(activation.avm2().proxy_namespace, "callProperty"),
-                        &args[..],
-                        activation,
-                    );
-                }
-            }
+        if let Some(qname) = Self::resolve_qname(multiname, activation)? {
+            let mut args = vec![qname.into()];
+            args.extend_from_slice(arguments);
+
+            return self.call_property(
+                &Multiname::new(activation.avm2().proxy_namespace, "callProperty"),
+                &args[..],
+                activation,
+            );
         }
 
         Err(format!(
@@ -178,25 +194,14 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         activation: &mut Activation<'_, 'gc>,
         multiname: &Multiname<'gc>,
     ) -> Result<bool, Error<'gc>> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    return Ok(self
-                        .call_property(
-                            &Multiname::new(activation.avm2().proxy_namespace, "deleteProperty"),
-                            &[qname.into()],
-                            activation,
-                        )?
-                        .coerce_to_boolean());
-                }
-            }
+        if let Some(qname) = Self::resolve_qname(multiname, activation)? {
+            return Ok(self
+                .call_property(
+                    &Multiname::new(activation.avm2().proxy_namespace, "deleteProperty"),
+                    &[qname.into()],
+                    activation,
+                )?
+                .coerce_to_boolean());
         }
 
         // Unknown properties on a dynamic class delete successfully.
@@ -211,14 +216,28 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         activation: &mut Activation<'_, 'gc>,
         name: &Multiname<'gc>,
     ) -> Result<bool, Error<'gc>> {
-        Ok(self
-            .call_property(
-                &Multiname::new(activation.avm2().proxy_namespace, "hasProperty"),
-                // this should probably pass the multiname as-is? See above
-                &[name.local_name().unwrap().into()],
-                activation,
-            )?
-            .coerce_to_boolean())
+        if let Some(qname) = Self::resolve_qname(name, activation)? {
+            return Ok(self
+                .call_property(
+                    &Multiname::new(activation.avm2().proxy_namespace, "hasProperty"),
+                    &[qname.into()],
+                    activation,
+                )?
+                .coerce_to_boolean());
+        }
+
+        // Match get_property_local: `"foo" in proxyInstance` on a dynamic,
+        // non-sealed Proxy reports false for an unmatched name instead of
+        // erroring; only a sealed class errors.
+        if !self
+            .instance_of_class_definition()
+            .map(|c| c.read().is_sealed())
+            .unwrap_or(false)
+        {
+            return Ok(false);
+        }
+
+        Err(format!("Cannot check for undefined property {:?}", name.local_name()).into())
     }
 
     fn get_next_enumerant(
@@ -259,4 +278,43 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
             activation,
         )
     }
+
+    /// Dispatches `proxy..child` and `proxy..@attr` to `Proxy.getDescendants`.
+    ///
+    /// `getDescendants` tells an attribute query from an element query by
+    /// the *type* of the argument it receives (`String` vs `QName`), not by
+    /// an `@` prefix on a `QName`'s local name — so an attribute query is
+    /// forwarded as a plain `"@name"` string, not a `QNameObject`, matching
+    /// `flash_proxy`'s real contract. Element queries still go through
+    /// `resolve_qname` so they keep the namespace a `Proxy` subclass
+    /// declared, same as `getProperty`.
+    fn get_descendants(
+        self,
+        activation: &mut Activation<'_, 'gc>,
+        multiname: &Multiname<'gc>,
+    ) -> Result<Value<'gc>, Error<'gc>> {
+        if let Some(qname) = Self::resolve_qname(multiname, activation)? {
+            let name: Value<'gc> = if multiname.is_attribute() {
+                AvmString::new_utf8(
+                    activation.context.gc_context,
+                    format!("@{}", qname.local_name(activation)?),
+                )
+                .into()
+            } else {
+                qname.into()
+            };
+
+            return self.call_property(
+                &Multiname::new(activation.avm2().proxy_namespace, "getDescendants"),
+                &[name],
+                activation,
+            );
+        }
+
+        Err(format!(
+            "Cannot get descendants of undefined property {:?}",
+            multiname.local_name()
+        )
+        .into())
+    }
 }
@@ -1,5 +1,6 @@
 use super::AvmAtom;
 use gc_arena::Collect;
+use std::collections::HashMap;
 
 macro_rules! define_common_strings {
     (
@@ -15,18 +16,62 @@ macro_rules! define_common_strings {
             $(
                 pub $field: AvmAtom<'gc>,
             )*
+
+            /// Maps the pointer identity of every atom above back to the
+            /// `CommonStr` it was interned for, so `classify` can answer
+            /// with a single hash lookup instead of comparing strings.
+            #[collect(require_static)]
+            classified: HashMap<*const (), CommonStr>,
         }
 
         impl<'gc> CommonStrings<'gc> {
             pub(super) fn new(mut intern_from_static: impl FnMut(&'static [u8]) -> AvmAtom<'gc>) -> Self {
+                let $ascii: [AvmAtom<'gc>; ASCII_CHARS_LEN] = std::array::from_fn(|i| {
+                    let c = &ASCII_CHARS[i];
+                    intern_from_static(std::slice::from_ref(c))
+                });
+                $(let $field = intern_from_static($str);)*
+
+                let mut classified = HashMap::new();
+                for (i, atom) in $ascii.iter().enumerate() {
+                    classified.insert(atom.as_ptr(), CommonStr::Ascii(i as u8));
+                }
+                $(classified.insert($field.as_ptr(), CommonStr::$field);)*
+
                 Self {
-                    $ascii: std::array::from_fn(|i| {
-                        let c = &ASCII_CHARS[i];
-                        intern_from_static(std::slice::from_ref(c))
-                    }),
-                    $($field: intern_from_static($str)),*
+                    $ascii,
+                    $($field,)*
+                    classified,
                 }
             }
+
+            /// Classifies `atom` as one of the common strings interned above,
+            /// if it is one.
+            ///
+            /// Round-trips for every entry built in `new` above, and returns
+            /// `None` for any atom interned through a different `AvmAtom`
+            /// than the one backing this `CommonStrings`, since its pointer
+            /// then can't appear in `classified`.
+            pub fn classify(&self, atom: AvmAtom<'gc>) -> Option<CommonStr> {
+                self.classified.get(&atom.as_ptr()).copied()
+            }
+
+            /// Convenience for `self.classify(atom) == Some(which)`.
+            pub fn is_common(&self, atom: AvmAtom<'gc>, which: CommonStr) -> bool {
+                self.classify(atom) == Some(which)
+            }
+        }
+
+        /// Mirrors the fields of [`CommonStrings`]; returned by
+        /// [`CommonStrings::classify`] to identify which common string an
+        /// [`AvmAtom`] was interned for.
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum CommonStr {
+            /// One of the 128 single-character ASCII atoms, carrying its byte value.
+            Ascii(u8),
+
+            $($field,)*
         }
     };
 }